@@ -1,19 +1,43 @@
 use glib::object::Cast;
 use gtk::{
-    self, AdjustmentExt, BoxExt, ButtonExt, ContainerExt, DialogExt, LabelExt, ScrolledWindowExt
+    self, AdjustmentExt, BoxExt, ButtonExt, CellLayoutExt, ContainerExt,
+    DialogExt, LabelExt, ListStoreExtManual, ScrolledWindowExt, SpinButtonExt, StaticType,
+    TreeViewColumnExt, TreeViewExt,
 };
 use gtk::{WidgetExt, GtkWindowExt};
+use libc;
 use pango;
 use sysinfo::{self, Pid, ProcessExt};
 
 use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fs;
+use std::io;
 use std::iter;
 use std::rc::Rc;
+use std::time::Instant;
 
 use graph::{Connecter, Graph};
 use notebook::NoteBook;
 use utils::{connect_graph, format_number, RotateVec};
 
+// Sampled values (cpu usage, deltas between two `/proc` reads, ...) can transiently come
+// back as NaN or infinite, which would otherwise corrupt a graph's autoscaling and leave
+// its curve permanently broken.
+trait FiniteOr {
+    fn finite_or_default(self) -> f64;
+}
+
+impl FiniteOr for f64 {
+    fn finite_or_default(self) -> f64 {
+        if self.is_finite() {
+            self
+        } else {
+            0.0
+        }
+    }
+}
+
 #[allow(dead_code)]
 pub struct ProcDialog {
     working_directory: gtk::Label,
@@ -25,6 +49,12 @@ pub struct ProcDialog {
     notebook: NoteBook,
     ram_usage_history: Rc<RefCell<Graph>>,
     cpu_usage_history: Rc<RefCell<Graph>>,
+    open_files_store: gtk::ListStore,
+    memory_maps_store: gtk::ListStore,
+    disk_io_history: Rc<RefCell<Graph>>,
+    prev_io: RefCell<(u64, u64, Instant)>,
+    threads_store: gtk::ListStore,
+    prev_thread_ticks: RefCell<(HashMap<i32, (u64, u64)>, Instant)>,
 }
 
 impl ProcDialog {
@@ -37,12 +67,416 @@ impl ProcDialog {
 
         let mut t = self.ram_usage_history.borrow_mut();
         t.data[0].move_start();
-        *t.data[0].get_mut(0).expect("cannot get data 0") = process.memory() as f64;
+        *t.data[0].get_mut(0).expect("cannot get data 0") = (process.memory() as f64).finite_or_default();
         t.invalidate();
         let mut t = self.cpu_usage_history.borrow_mut();
         t.data[0].move_start();
-        *t.data[0].get_mut(0).expect("cannot get data 0") = process.cpu_usage() as f64;
+        *t.data[0].get_mut(0).expect("cannot get data 0") = (process.cpu_usage() as f64).finite_or_default();
         t.invalidate();
+
+        update_open_files(&self.open_files_store, self.pid);
+        update_memory_maps(&self.memory_maps_store, self.pid);
+
+        let now = Instant::now();
+        let mut prev_io = self.prev_io.borrow_mut();
+        if let Some((read_bytes, write_bytes)) = read_proc_io(self.pid) {
+            let elapsed = now.duration_since(prev_io.2).as_secs_f64();
+            // `byte_size_labels` expects its input in kB (it's shared with `ram_usage_history`,
+            // which is fed `process.memory()` directly, itself a KiB value), so convert the
+            // bytes/sec rate down to kB/sec before storing it.
+            let (read_rate, write_rate) = if elapsed > 0. {
+                ((read_bytes.saturating_sub(prev_io.0) as f64 / elapsed / 1_024.).finite_or_default(),
+                 (write_bytes.saturating_sub(prev_io.1) as f64 / elapsed / 1_024.).finite_or_default())
+            } else {
+                (0., 0.)
+            };
+            *prev_io = (read_bytes, write_bytes, now);
+
+            let mut t = self.disk_io_history.borrow_mut();
+            t.data[0].move_start();
+            *t.data[0].get_mut(0).expect("cannot get data 0") = read_rate;
+            t.data[1].move_start();
+            *t.data[1].get_mut(0).expect("cannot get data 0") = write_rate;
+            t.invalidate();
+        }
+
+        let now = Instant::now();
+        let mut prev_threads = self.prev_thread_ticks.borrow_mut();
+        let elapsed = now.duration_since(prev_threads.1).as_secs_f64();
+        let mut next_ticks = HashMap::new();
+        self.threads_store.clear();
+        for thread in list_threads(self.pid) {
+            let cpu_usage = match prev_threads.0.get(&thread.tid) {
+                Some(&(prev_utime, prev_stime)) if elapsed > 0. => {
+                    let delta_ticks = (thread.utime + thread.stime)
+                        .saturating_sub(prev_utime + prev_stime);
+                    (delta_ticks as f64 / clock_ticks_per_sec() / elapsed * 100.)
+                        .finite_or_default()
+                }
+                _ => 0.,
+            };
+            next_ticks.insert(thread.tid, (thread.utime, thread.stime));
+            insert_thread_row(&self.threads_store, &thread, cpu_usage);
+        }
+        *prev_threads = (next_ticks, now);
+    }
+}
+
+struct ThreadInfo {
+    tid: i32,
+    name: String,
+    state: String,
+    utime: u64,
+    stime: u64,
+}
+
+// The kernel reports CPU times in clock ticks; `sysconf(_SC_CLK_TCK)` gives the number of
+// ticks per second needed to convert them into real time.
+#[cfg(target_os = "linux")]
+fn clock_ticks_per_sec() -> f64 {
+    let ticks = unsafe { libc::sysconf(libc::_SC_CLK_TCK) };
+    if ticks > 0 { ticks as f64 } else { 100. }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn clock_ticks_per_sec() -> f64 {
+    100.
+}
+
+// Parses the handful of fields we need out of `/proc/<pid>/task/<tid>/stat`. The comm field
+// is wrapped in parentheses and may itself contain spaces, so we split on the last ')'
+// rather than whitespace to find where the fixed-format fields begin.
+#[cfg(target_os = "linux")]
+fn parse_thread_stat(tid: i32, content: &str) -> Option<ThreadInfo> {
+    let name_start = content.find('(')?;
+    let name_end = content.rfind(')')?;
+    let name = content[name_start + 1..name_end].to_owned();
+    let rest: Vec<&str> = content[name_end + 1..].split_whitespace().collect();
+    let state = rest.first()?.to_string();
+    let utime = rest.get(11)?.parse::<u64>().ok()?;
+    let stime = rest.get(12)?.parse::<u64>().ok()?;
+    Some(ThreadInfo { tid, name, state, utime, stime })
+}
+
+#[cfg(target_os = "linux")]
+fn list_threads(pid: Pid) -> Vec<ThreadInfo> {
+    let dir = match fs::read_dir(format!("/proc/{}/task", pid)) {
+        Ok(dir) => dir,
+        Err(_) => return Vec::new(),
+    };
+    let mut threads = Vec::new();
+    for entry in dir.filter_map(|e| e.ok()) {
+        let tid = match entry.file_name().to_str().and_then(|s| s.parse::<i32>().ok()) {
+            Some(tid) => tid,
+            None => continue,
+        };
+        if let Ok(content) = fs::read_to_string(entry.path().join("stat")) {
+            if let Some(thread) = parse_thread_stat(tid, &content) {
+                threads.push(thread);
+            }
+        }
+    }
+    threads.sort_by_key(|t| t.tid);
+    threads
+}
+
+#[cfg(not(target_os = "linux"))]
+fn list_threads(_pid: Pid) -> Vec<ThreadInfo> {
+    Vec::new()
+}
+
+fn insert_thread_row(store: &gtk::ListStore, thread: &ThreadInfo, cpu_usage: f64) {
+    let cpu_usage_text = format!("{:.1}%", cpu_usage);
+    store.insert_with_values(
+        None,
+        &[0, 1, 2, 3],
+        &[&thread.tid, &thread.name, &thread.state, &cpu_usage_text],
+    );
+}
+
+// Populates `store` with the process' current threads, with CPU usage at 0% since there is
+// no prior tick to compute a delta against yet; `ProcDialog::update` fills in real usage.
+fn populate_threads_store(store: &gtk::ListStore, pid: Pid) {
+    store.clear();
+    for thread in list_threads(pid) {
+        insert_thread_row(store, &thread, 0.);
+    }
+}
+
+// Parses `read_bytes`/`write_bytes` out of `/proc/<pid>/io`.
+#[cfg(target_os = "linux")]
+fn read_proc_io(pid: Pid) -> Option<(u64, u64)> {
+    let content = fs::read_to_string(format!("/proc/{}/io", pid)).ok()?;
+    let mut read_bytes = None;
+    let mut write_bytes = None;
+    for line in content.lines() {
+        if let Some(value) = line.strip_prefix("read_bytes:") {
+            read_bytes = value.trim().parse::<u64>().ok();
+        } else if let Some(value) = line.strip_prefix("write_bytes:") {
+            write_bytes = value.trim().parse::<u64>().ok();
+        }
+    }
+    Some((read_bytes?, write_bytes?))
+}
+
+#[cfg(not(target_os = "linux"))]
+fn read_proc_io(_pid: Pid) -> Option<(u64, u64)> {
+    None
+}
+
+struct MemoryMap {
+    range: String,
+    perms: String,
+    offset: String,
+    dev: String,
+    inode: String,
+    pathname: String,
+    rss: u64,
+    private: u64,
+    shared: u64,
+}
+
+// Parses a single `/proc/<pid>/maps` line, e.g.:
+// "7f3b2c1b7000-7f3b2c1b9000 r--p 00000000 08:01 1234 /lib/x86_64-linux-gnu/libc.so.6"
+#[cfg(target_os = "linux")]
+fn parse_maps_line(line: &str) -> Option<MemoryMap> {
+    let mut fields = line.splitn(6, ' ').filter(|s| !s.is_empty());
+    let range = fields.next()?.to_owned();
+    let perms = fields.next()?.to_owned();
+    let offset = fields.next()?.to_owned();
+    let dev = fields.next()?.to_owned();
+    let inode = fields.next()?.to_owned();
+    let pathname = fields.next().map(|s| s.trim().to_owned()).unwrap_or_default();
+    Some(MemoryMap {
+        range,
+        perms,
+        offset,
+        dev,
+        inode,
+        pathname,
+        rss: 0,
+        private: 0,
+        shared: 0,
+    })
+}
+
+// smaps entries look like "Rss:                 12 kB"; extracts the number of kB.
+#[cfg(target_os = "linux")]
+fn parse_smaps_field(line: &str, name: &str) -> Option<u64> {
+    let rest = line.strip_prefix(name)?;
+    rest.trim().trim_end_matches("kB").trim().parse::<u64>().ok()
+}
+
+// `smaps` repeats the `maps` address-range header before each mapping's fields, but it also
+// has per-mapping lines like `VmFlags: rd mr mw me` that `parse_maps_line` happily parses as
+// a (nonsensical) header too. Only the real header's first field is a `start-end` hex range,
+// so check that explicitly instead of reusing `parse_maps_line`.
+#[cfg(target_os = "linux")]
+fn is_smaps_header(line: &str) -> bool {
+    let range = match line.split(' ').next() {
+        Some(range) => range,
+        None => return false,
+    };
+    let dash = match range.find('-') {
+        Some(dash) => dash,
+        None => return false,
+    };
+    let (start, end) = (&range[..dash], &range[dash + 1..]);
+    !start.is_empty()
+        && !end.is_empty()
+        && start.chars().all(|c| c.is_ascii_hexdigit())
+        && end.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+#[cfg(target_os = "linux")]
+fn list_memory_maps(pid: Pid) -> Vec<MemoryMap> {
+    let maps = match fs::read_to_string(format!("/proc/{}/maps", pid)) {
+        Ok(maps) => maps,
+        Err(_) => return Vec::new(),
+    };
+    let mut entries: Vec<MemoryMap> = maps.lines().filter_map(parse_maps_line).collect();
+
+    // `smaps` is only available on newer kernels and requires extra permissions in some
+    // sandboxes, so its absence shouldn't prevent showing the basic `maps` information.
+    if let Ok(smaps) = fs::read_to_string(format!("/proc/{}/smaps", pid)) {
+        let mut current = 0usize;
+        let mut shared_clean = 0u64;
+        let mut shared_dirty = 0u64;
+        let mut private_clean = 0u64;
+        let mut private_dirty = 0u64;
+        for line in smaps.lines() {
+            if is_smaps_header(line) {
+                if current > 0 {
+                    if let Some(entry) = entries.get_mut(current - 1) {
+                        entry.shared = (shared_clean + shared_dirty) * 1_024;
+                        entry.private = (private_clean + private_dirty) * 1_024;
+                    }
+                }
+                current += 1;
+                shared_clean = 0;
+                shared_dirty = 0;
+                private_clean = 0;
+                private_dirty = 0;
+                continue;
+            }
+            if let Some(rss) = parse_smaps_field(line, "Rss:") {
+                if let Some(entry) = entries.get_mut(current.saturating_sub(1)) {
+                    entry.rss = rss * 1_024;
+                }
+            } else if let Some(v) = parse_smaps_field(line, "Shared_Clean:") {
+                shared_clean = v;
+            } else if let Some(v) = parse_smaps_field(line, "Shared_Dirty:") {
+                shared_dirty = v;
+            } else if let Some(v) = parse_smaps_field(line, "Private_Clean:") {
+                private_clean = v;
+            } else if let Some(v) = parse_smaps_field(line, "Private_Dirty:") {
+                private_dirty = v;
+            }
+        }
+        if current > 0 {
+            if let Some(entry) = entries.get_mut(current - 1) {
+                entry.shared = (shared_clean + shared_dirty) * 1_024;
+                entry.private = (private_clean + private_dirty) * 1_024;
+            }
+        }
+    }
+    entries
+}
+
+#[cfg(not(target_os = "linux"))]
+fn list_memory_maps(_pid: Pid) -> Vec<MemoryMap> {
+    Vec::new()
+}
+
+fn update_memory_maps(store: &gtk::ListStore, pid: Pid) {
+    store.clear();
+    for entry in list_memory_maps(pid) {
+        let rss = format_number(entry.rss);
+        let private = format_number(entry.private);
+        let shared = format_number(entry.shared);
+        store.insert_with_values(
+            None,
+            &[0, 1, 2, 3, 4, 5, 6, 7, 8],
+            &[
+                &entry.range,
+                &entry.perms,
+                &entry.offset,
+                &entry.dev,
+                &entry.inode,
+                &entry.pathname,
+                &rss,
+                &private,
+                &shared,
+            ],
+        );
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum FdKind {
+    Regular,
+    Directory,
+    Socket,
+    Pipe,
+    AnonInode,
+    Other,
+}
+
+impl FdKind {
+    fn as_str(&self) -> &'static str {
+        match *self {
+            FdKind::Regular => "Regular file",
+            FdKind::Directory => "Directory",
+            FdKind::Socket => "Socket",
+            FdKind::Pipe => "Pipe",
+            FdKind::AnonInode => "Anonymous inode",
+            FdKind::Other => "Other",
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn classify_target(target: &str) -> FdKind {
+    if target.starts_with("socket:[") {
+        FdKind::Socket
+    } else if target.starts_with("pipe:[") {
+        FdKind::Pipe
+    } else if target.starts_with("anon_inode:") {
+        FdKind::AnonInode
+    } else if fs::metadata(target).map(|m| m.is_dir()).unwrap_or(false) {
+        FdKind::Directory
+    } else if target.starts_with('/') {
+        FdKind::Regular
+    } else {
+        FdKind::Other
+    }
+}
+
+// Reads the "flags:" line of `/proc/<pid>/fdinfo/<fd>` and turns the access-mode bits
+// (the low two bits of `O_ACCMODE`) into a human-readable string.
+#[cfg(target_os = "linux")]
+fn read_access_mode(pid: Pid, fd: &str) -> String {
+    let path = format!("/proc/{}/fdinfo/{}", pid, fd);
+    let content = match fs::read_to_string(&path) {
+        Ok(content) => content,
+        Err(_) => return "?".to_owned(),
+    };
+    for line in content.lines() {
+        if let Some(value) = line.strip_prefix("flags:") {
+            if let Ok(flags) = i32::from_str_radix(value.trim(), 8) {
+                return match flags & 0o3 {
+                    0 => "read-only".to_owned(),
+                    1 => "write-only".to_owned(),
+                    2 => "read/write".to_owned(),
+                    _ => "?".to_owned(),
+                };
+            }
+        }
+    }
+    "?".to_owned()
+}
+
+#[cfg(target_os = "linux")]
+fn list_open_files(pid: Pid) -> Vec<(u32, String, String, String)> {
+    let dir = match fs::read_dir(format!("/proc/{}/fd", pid)) {
+        Ok(dir) => dir,
+        Err(_) => return Vec::new(),
+    };
+    let mut files = Vec::new();
+    for entry in dir.filter_map(|e| e.ok()) {
+        let fd_name = entry.file_name();
+        let fd_name = match fd_name.to_str() {
+            Some(fd_name) => fd_name.to_owned(),
+            None => continue,
+        };
+        let fd_number = match fd_name.parse::<u32>() {
+            Ok(fd_number) => fd_number,
+            Err(_) => continue,
+        };
+        let target = fs::read_link(entry.path())
+            .map(|p| p.display().to_string())
+            .unwrap_or_else(|_| "?".to_owned());
+        let kind = classify_target(&target);
+        let mode = read_access_mode(pid, &fd_name);
+        files.push((fd_number, target, kind.as_str().to_owned(), mode));
+    }
+    files.sort_by_key(|&(fd, ..)| fd);
+    files
+}
+
+#[cfg(not(target_os = "linux"))]
+fn list_open_files(_pid: Pid) -> Vec<(u32, String, String, String)> {
+    Vec::new()
+}
+
+fn update_open_files(store: &gtk::ListStore, pid: Pid) {
+    store.clear();
+    for (fd, target, kind, mode) in list_open_files(pid) {
+        store.insert_with_values(
+            None,
+            &[0, 1, 2, 3],
+            &[&fd, &target, &kind, &mode],
+        );
     }
 }
 
@@ -99,6 +533,32 @@ fn create_and_add_new_label(scroll: &gtk::Box, title: &str, text: &str) -> gtk::
     text
 }
 
+// Shared label formatter for the RAM and disk I/O graphs' kB/MB/GB/TB axis labels;
+// `unit_suffix` adds a "/s" for throughput graphs.
+fn byte_size_labels(v: f64, unit_suffix: &str) -> [String; 4] {
+    if v < 100_000. {
+        [v.to_string(),
+         format!("{}", v / 2.),
+         "0".to_string(),
+         format!("kB{}", unit_suffix)]
+    } else if v < 10_000_000. {
+        [format!("{:.1}", v / 1_024f64),
+         format!("{:.1}", v / 2_048f64),
+         "0".to_string(),
+         format!("MB{}", unit_suffix)]
+    } else if v < 10_000_000_000. {
+        [format!("{:.1}", v / 1_048_576f64),
+         format!("{:.1}", v / 2_097_152f64),
+         "0".to_string(),
+         format!("GB{}", unit_suffix)]
+    } else {
+        [format!("{:.1}", v / 1_073_741_824f64),
+         format!("{:.1}", v / 1_073_741_824f64),
+         "0".to_string(),
+         format!("TB{}", unit_suffix)]
+    }
+}
+
 fn compute_running_since(
     process: &sysinfo::Process,
     start_time: u64,
@@ -111,6 +571,92 @@ fn compute_running_since(
     }
 }
 
+#[cfg(unix)]
+fn send_signal(pid: Pid, signal: libc::c_int) -> io::Result<()> {
+    let ret = unsafe { libc::kill(pid as libc::pid_t, signal) };
+    if ret == 0 {
+        Ok(())
+    } else {
+        Err(io::Error::last_os_error())
+    }
+}
+
+#[cfg(unix)]
+fn set_priority(pid: Pid, priority: i32) -> io::Result<()> {
+    let ret = unsafe { libc::setpriority(libc::PRIO_PROCESS, pid as libc::id_t, priority) };
+    if ret == 0 {
+        Ok(())
+    } else {
+        Err(io::Error::last_os_error())
+    }
+}
+
+#[cfg(not(unix))]
+fn send_signal(_pid: Pid, _signal: i32) -> io::Result<()> {
+    Err(io::Error::new(io::ErrorKind::Other, "not supported on this platform"))
+}
+
+#[cfg(not(unix))]
+fn set_priority(_pid: Pid, _priority: i32) -> io::Result<()> {
+    Err(io::Error::new(io::ErrorKind::Other, "not supported on this platform"))
+}
+
+// Shows a Yes/No confirmation dialog for destructive actions (terminate/kill) and returns
+// whether the user confirmed.
+fn confirm(parent: &gtk::Window, message: &str) -> bool {
+    let dialog = gtk::MessageDialog::new(
+        Some(parent),
+        gtk::DialogFlags::MODAL,
+        gtk::MessageType::Warning,
+        gtk::ButtonsType::YesNo,
+        message,
+    );
+    let answer = dialog.run();
+    dialog.destroy();
+    answer == gtk::ResponseType::Yes
+}
+
+fn report_error(parent: &gtk::Window, message: &str) {
+    let dialog = gtk::MessageDialog::new(
+        Some(parent),
+        gtk::DialogFlags::MODAL,
+        gtk::MessageType::Error,
+        gtk::ButtonsType::Ok,
+        message,
+    );
+    dialog.run();
+    dialog.destroy();
+}
+
+// If the last control action failed because we don't have permission to act on this
+// process, there is no point in leaving the controls enabled: disable them so the user
+// isn't invited to retry a privileged operation that will only fail again.
+fn disable_on_eperm(err: &io::Error, buttons: &[&gtk::Button]) {
+    if err.raw_os_error() == Some(libc::EPERM) {
+        for button in buttons {
+            button.set_sensitive(false);
+        }
+    }
+}
+
+// Builds a plain `gtk::TreeView` of string/numeric columns backed by `store`, one
+// `TreeViewColumn` per entry in `column_titles`, in the same order as the store's columns.
+fn create_list_tree_view(store: &gtk::ListStore, column_titles: &[&str]) -> gtk::TreeView {
+    let tree_view = gtk::TreeView::new_with_model(store);
+    tree_view.set_headers_visible(true);
+
+    for (i, title) in column_titles.iter().enumerate() {
+        let cell = gtk::CellRendererText::new();
+        let column = gtk::TreeViewColumn::new();
+        column.set_title(title);
+        column.set_resizable(true);
+        CellLayoutExt::pack_start(&column, &cell, true);
+        CellLayoutExt::add_attribute(&column, &cell, "text", i as i32);
+        tree_view.append_column(&column);
+    }
+    tree_view
+}
+
 pub fn create_process_dialog(
     process: &sysinfo::Process,
     window: &gtk::ApplicationWindow,
@@ -163,7 +709,28 @@ pub fn create_process_dialog(
 
     scroll.add(&labels);
 
+    let control_box = gtk::Box::new(gtk::Orientation::Horizontal, 5);
+    control_box.set_margin_top(5);
+    control_box.set_margin_bottom(5);
+    control_box.set_margin_start(5);
+    control_box.set_margin_end(5);
+
+    let terminate_button = gtk::Button::new_with_label("Terminate");
+    let kill_button = gtk::Button::new_with_label("Kill");
+    let stop_button = gtk::Button::new_with_label("Stop");
+    let continue_button = gtk::Button::new_with_label("Continue");
+    control_box.pack_start(&terminate_button, false, true, 0);
+    control_box.pack_start(&kill_button, false, true, 0);
+    control_box.pack_start(&stop_button, false, true, 0);
+    control_box.pack_start(&continue_button, false, true, 0);
+
+    control_box.pack_start(&gtk::Label::new("priority:"), false, true, 0);
+    let priority_spin = gtk::SpinButton::new_with_range(-20., 19., 1.);
+    priority_spin.set_value(0.);
+    control_box.pack_start(&priority_spin, false, true, 0);
+
     vertical_layout.pack_start(&scroll, true, true, 0);
+    vertical_layout.pack_start(&control_box, false, true, 0);
     vertical_layout.pack_start(&close_button, false, true, 0);
 
     notebook.create_tab("Information", &vertical_layout);
@@ -196,41 +763,91 @@ pub fn create_process_dialog(
 
     ram_usage_history.push(RotateVec::new(iter::repeat(0f64).take(61).collect()),
                            "", None);
-    ram_usage_history.set_label_callbacks(Some(Box::new(|v| {
-        if v < 100_000. {
-            [v.to_string(),
-             format!("{}", v / 2.),
-             "0".to_string(),
-             "kB".to_string()]
-        } else if v < 10_000_000. {
-            [format!("{:.1}", v / 1_024f64),
-             format!("{:.1}", v / 2_048f64),
-             "0".to_string(),
-             "MB".to_string()]
-        } else if v < 10_000_000_000. {
-            [format!("{:.1}", v / 1_048_576f64),
-             format!("{:.1}", v / 2_097_152f64),
-             "0".to_string(),
-             "GB".to_string()]
-        } else {
-            [format!("{:.1}", v / 1_073_741_824f64),
-             format!("{:.1}", v / 1_073_741_824f64),
-             "0".to_string(),
-             "TB".to_string()]
-        }
-    })));
+    ram_usage_history.set_label_callbacks(Some(Box::new(|v| byte_size_labels(v, ""))));
     vertical_layout.add(&gtk::Label::new("Memory usage"));
     ram_usage_history.attach_to(&vertical_layout);
     ram_usage_history.invalidate();
     let ram_usage_history = connect_graph(ram_usage_history);
 
+    let mut disk_io_history = Graph::new(None, true);
+    disk_io_history.set_display_labels(false);
+    disk_io_history.push(RotateVec::new(iter::repeat(0f64).take(61).collect()),
+                         "read", None);
+    disk_io_history.push(RotateVec::new(iter::repeat(0f64).take(61).collect()),
+                         "write", None);
+    disk_io_history.set_label_callbacks(Some(Box::new(|v| byte_size_labels(v, "/s"))));
+    vertical_layout.add(&gtk::Label::new("Disk I/O"));
+    disk_io_history.attach_to(&vertical_layout);
+    disk_io_history.invalidate();
+    let disk_io_history = connect_graph(disk_io_history);
+
     scroll.add(&vertical_layout);
-    scroll.connect_show(clone!(ram_usage_history, cpu_usage_history => move |_| {
+    scroll.connect_show(clone!(ram_usage_history, cpu_usage_history, disk_io_history => move |_| {
         ram_usage_history.borrow().show_all();
         cpu_usage_history.borrow().show_all();
+        disk_io_history.borrow().show_all();
     }));
     notebook.create_tab("Resources usage", &scroll);
 
+    //
+    // OPEN FILES TAB
+    //
+    let open_files_store = gtk::ListStore::new(&[
+        u32::static_type(),
+        gtk::Type::String,
+        gtk::Type::String,
+        gtk::Type::String,
+    ]);
+    let open_files_view = create_list_tree_view(
+        &open_files_store,
+        &["fd", "target", "type", "mode"],
+    );
+    update_open_files(&open_files_store, process.pid());
+    let scroll = gtk::ScrolledWindow::new(None::<&gtk::Adjustment>, None::<&gtk::Adjustment>);
+    scroll.set_policy(gtk::PolicyType::Automatic, gtk::PolicyType::Automatic);
+    scroll.add(&open_files_view);
+    notebook.create_tab("Open Files", &scroll);
+
+    //
+    // MEMORY MAPS TAB
+    //
+    let memory_maps_store = gtk::ListStore::new(&[
+        gtk::Type::String,
+        gtk::Type::String,
+        gtk::Type::String,
+        gtk::Type::String,
+        gtk::Type::String,
+        gtk::Type::String,
+        gtk::Type::String,
+        gtk::Type::String,
+        gtk::Type::String,
+    ]);
+    let memory_maps_view = create_list_tree_view(
+        &memory_maps_store,
+        &["range", "perms", "offset", "dev", "inode", "pathname", "rss", "private", "shared"],
+    );
+    update_memory_maps(&memory_maps_store, process.pid());
+    let scroll = gtk::ScrolledWindow::new(None::<&gtk::Adjustment>, None::<&gtk::Adjustment>);
+    scroll.set_policy(gtk::PolicyType::Automatic, gtk::PolicyType::Automatic);
+    scroll.add(&memory_maps_view);
+    notebook.create_tab("Memory Maps", &scroll);
+
+    //
+    // THREADS TAB
+    //
+    let threads_store = gtk::ListStore::new(&[
+        i32::static_type(),
+        gtk::Type::String,
+        gtk::Type::String,
+        gtk::Type::String,
+    ]);
+    let threads_view = create_list_tree_view(&threads_store, &["tid", "name", "state", "cpu usage"]);
+    populate_threads_store(&threads_store, process.pid());
+    let scroll = gtk::ScrolledWindow::new(None::<&gtk::Adjustment>, None::<&gtk::Adjustment>);
+    scroll.set_policy(gtk::PolicyType::Automatic, gtk::PolicyType::Automatic);
+    scroll.add(&threads_view);
+    notebook.create_tab("Threads", &scroll);
+
     let area = popup.get_content_area();
     area.set_margin_top(0);
     area.set_margin_bottom(0);
@@ -252,12 +869,74 @@ pub fn create_process_dialog(
         pop.destroy();
     });
 
+    let pid = process.pid();
+    let buttons = [
+        terminate_button.clone(),
+        kill_button.clone(),
+        stop_button.clone(),
+        continue_button.clone(),
+    ];
+    let pop = popup.clone();
+    let other_buttons = buttons.clone();
+    terminate_button.connect_clicked(move |_| {
+        if !confirm(&pop, "Are you sure you want to terminate this process?") {
+            return;
+        }
+        if let Err(err) = send_signal(pid, libc::SIGTERM) {
+            report_error(&pop, &format!("Failed to terminate process: {}", err));
+            disable_on_eperm(&err, &other_buttons.iter().collect::<Vec<_>>());
+        }
+    });
+    let pop = popup.clone();
+    let other_buttons = buttons.clone();
+    kill_button.connect_clicked(move |_| {
+        if !confirm(&pop, "Are you sure you want to kill this process?") {
+            return;
+        }
+        if let Err(err) = send_signal(pid, libc::SIGKILL) {
+            report_error(&pop, &format!("Failed to kill process: {}", err));
+            disable_on_eperm(&err, &other_buttons.iter().collect::<Vec<_>>());
+        }
+    });
+    let pop = popup.clone();
+    let other_buttons = buttons.clone();
+    stop_button.connect_clicked(move |_| {
+        if let Err(err) = send_signal(pid, libc::SIGSTOP) {
+            report_error(&pop, &format!("Failed to stop process: {}", err));
+            disable_on_eperm(&err, &other_buttons.iter().collect::<Vec<_>>());
+        }
+    });
+    let pop = popup.clone();
+    let other_buttons = buttons.clone();
+    continue_button.connect_clicked(move |_| {
+        if let Err(err) = send_signal(pid, libc::SIGCONT) {
+            report_error(&pop, &format!("Failed to continue process: {}", err));
+            disable_on_eperm(&err, &other_buttons.iter().collect::<Vec<_>>());
+        }
+    });
+    let pop = popup.clone();
+    priority_spin.connect_value_changed(move |spin| {
+        if let Err(err) = set_priority(pid, spin.get_value() as i32) {
+            report_error(&pop, &format!("Failed to set process priority: {}", err));
+            if err.raw_os_error() == Some(libc::EPERM) {
+                spin.set_sensitive(false);
+            }
+        }
+    });
+
     if let Some(adjust) = scroll.get_vadjustment() {
         adjust.set_value(0.);
         scroll.set_vadjustment(&adjust);
     }
     ram_usage_history.connect_to_window_events();
     cpu_usage_history.connect_to_window_events();
+    disk_io_history.connect_to_window_events();
+
+    let (initial_read, initial_write) = read_proc_io(process.pid()).unwrap_or((0, 0));
+    let initial_ticks = list_threads(process.pid())
+        .into_iter()
+        .map(|t| (t.tid, (t.utime, t.stime)))
+        .collect();
 
     ProcDialog {
         working_directory,
@@ -269,5 +948,11 @@ pub fn create_process_dialog(
         notebook,
         ram_usage_history,
         cpu_usage_history,
+        open_files_store,
+        memory_maps_store,
+        disk_io_history,
+        prev_io: RefCell::new((initial_read, initial_write, Instant::now())),
+        threads_store,
+        prev_thread_ticks: RefCell::new((initial_ticks, Instant::now())),
     }
 }